@@ -2,6 +2,9 @@ use std::mem::transmute;
 use std::fmt;
 use std::error::Error;
 use std::ascii::AsciiExt;
+use std::convert::TryFrom;
+use std::ops::{Deref, Index, Range};
+use std::str;
 
 use AsciiCast;
 
@@ -313,10 +316,7 @@ impl Ascii {
     /// ```
     #[inline]
     pub fn from_byte(ch: u8) -> Result<Ascii, ()> {
-        unsafe{if ch <= 0x7F {
-            return Ok(ch.to_ascii_nocheck());
-        }}
-        Err(())
+        Ascii::try_from(ch).map_err(|_| ())
     }
 
     /// Converts an ASCII character into a `u8`.
@@ -458,6 +458,210 @@ impl Ascii {
     pub fn is_hex(&self) -> bool {
         self.is_digit() || (self.as_byte() | 32u8).wrapping_sub(b'a') < 6
     }
+
+    /// Converts the character to the value it represents in `radix`,
+    /// treating letters case-insensitively, or `None` if it isn't a valid
+    /// digit in that radix.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ascii::Ascii;
+    /// assert_eq!(Ascii::_7.to_digit(10), Some(7));
+    /// assert_eq!(Ascii::f.to_digit(16), Some(15));
+    /// assert_eq!(Ascii::F.to_digit(16), Some(15));
+    /// assert_eq!(Ascii::g.to_digit(16), None);
+    /// assert_eq!(Ascii::_9.to_digit(2), None);
+    /// ```
+    pub fn to_digit(&self, radix: u32) -> Option<u32> {
+        assert!((2..=36).contains(&radix), "to_digit: radix must be in the range 2..=36");
+        let byte = self.as_byte();
+        let value = if byte.wrapping_sub(b'0') < 10 {
+            (byte - b'0') as u32
+        } else {
+            let c = byte | 0b010_0000;
+            if c.wrapping_sub(b'a') < 26 {
+                (c - b'a') as u32 + 10
+            } else {
+                return None;
+            }
+        };
+        if value < radix { Some(value) } else { None }
+    }
+
+    /// Converts a number to the character that represents it in `radix`,
+    /// using a lowercase letter for values `10` and above, or `None` if
+    /// `num` is not representable in that radix.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ascii::Ascii;
+    /// assert_eq!(Ascii::from_digit(1, 2), Some(Ascii::_1));
+    /// assert_eq!(Ascii::from_digit(15, 16), Some(Ascii::f));
+    /// assert_eq!(Ascii::from_digit(10, 10), None);
+    /// ```
+    pub fn from_digit(num: u32, radix: u32) -> Option<Ascii> {
+        assert!((2..=36).contains(&radix), "from_digit: radix must be in the range 2..=36");
+        if num >= radix {
+            return None;
+        }
+        let byte = if num < 10 {
+            b'0' + num as u8
+        } else {
+            b'a' + (num - 10) as u8
+        };
+        unsafe { Some(byte.to_ascii_nocheck()) }
+    }
+
+    /// Returns an iterator over the escaped form of this character, the way
+    /// it would be written in Rust source code.
+    ///
+    /// `Tab`, `LineFeed`, `CarriageReturn`, `BackSlash`, `Apostrophe` and
+    /// `Quotation` get their usual one-letter escape (e.g. `\n`); any other
+    /// character accepted by `is_graph` or `is_blank` passes through
+    /// unchanged; everything else (the remaining control characters and
+    /// `DEL`) is written as `\xHH`, with `HH` the byte value in uppercase
+    /// hex.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ascii::Ascii;
+    /// let mut escaped = Ascii::Tab.escape_default();
+    /// assert_eq!(escaped.next(), Some(Ascii::BackSlash));
+    /// assert_eq!(escaped.next(), Some(Ascii::t));
+    /// assert_eq!(escaped.next(), None);
+    ///
+    /// assert_eq!(Ascii::A.escape_default().collect::<Vec<_>>(), vec![Ascii::A]);
+    /// ```
+    #[inline]
+    pub fn escape_default(&self) -> AsciiEscapeDefault {
+        fn one(ch: Ascii) -> ([Ascii; 4], usize) {
+            ([ch, Ascii::Null, Ascii::Null, Ascii::Null], 1)
+        }
+        fn two(first: Ascii, second: Ascii) -> ([Ascii; 4], usize) {
+            ([first, second, Ascii::Null, Ascii::Null], 2)
+        }
+
+        let (buf, len) = match *self {
+            Ascii::Tab => two(Ascii::BackSlash, Ascii::t),
+            Ascii::LineFeed => two(Ascii::BackSlash, Ascii::n),
+            Ascii::CarriageReturn => two(Ascii::BackSlash, Ascii::r),
+            Ascii::BackSlash => two(Ascii::BackSlash, Ascii::BackSlash),
+            Ascii::Apostrophe => two(Ascii::BackSlash, Ascii::Apostrophe),
+            Ascii::Quotation => two(Ascii::BackSlash, Ascii::Quotation),
+            ch if ch.is_graph() || ch.is_blank() => one(ch),
+            ch => {
+                let byte = ch.as_byte();
+                ([Ascii::BackSlash, Ascii::x, HEX_DIGITS[(byte >> 4) as usize],
+                  HEX_DIGITS[(byte & 0xF) as usize]], 4)
+            }
+        };
+        AsciiEscapeDefault { buf: buf, range: 0..len }
+    }
+
+    /// Converts the character to its uppercase equivalent.
+    ///
+    /// ASCII letters `'a'` to `'z'` are mapped to `'A'` to `'Z'`; all other
+    /// characters are unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ascii::Ascii;
+    /// assert_eq!(Ascii::a.to_uppercase(), Ascii::A);
+    /// assert_eq!(Ascii::A.to_uppercase(), Ascii::A);
+    /// assert_eq!(Ascii::Dot.to_uppercase(), Ascii::Dot);
+    /// ```
+    #[inline]
+    pub fn to_uppercase(&self) -> Ascii {
+        if self.is_lowercase() {
+            unsafe { (self.as_byte() & !0b010_0000).to_ascii_nocheck() }
+        } else {
+            *self
+        }
+    }
+
+    /// Converts the character to its lowercase equivalent.
+    ///
+    /// ASCII letters `'A'` to `'Z'` are mapped to `'a'` to `'z'`; all other
+    /// characters are unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ascii::Ascii;
+    /// assert_eq!(Ascii::A.to_lowercase(), Ascii::a);
+    /// assert_eq!(Ascii::a.to_lowercase(), Ascii::a);
+    /// assert_eq!(Ascii::Dot.to_lowercase(), Ascii::Dot);
+    /// ```
+    #[inline]
+    pub fn to_lowercase(&self) -> Ascii {
+        if self.is_uppercase() {
+            unsafe { (self.as_byte() | 0b010_0000).to_ascii_nocheck() }
+        } else {
+            *self
+        }
+    }
+
+    /// Checks that two characters are equal, ignoring case.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ascii::Ascii;
+    /// assert!(Ascii::A.eq_ignore_case(&Ascii::a));
+    /// assert!(!Ascii::A.eq_ignore_case(&Ascii::B));
+    /// ```
+    #[inline]
+    pub fn eq_ignore_case(&self, other: &Ascii) -> bool {
+        self.to_lowercase() == other.to_lowercase()
+    }
+}
+
+/// Uppercase hex digits `0`-`F`, indexed by value.
+const HEX_DIGITS: [Ascii; 16] = [
+    Ascii::_0, Ascii::_1, Ascii::_2, Ascii::_3,
+    Ascii::_4, Ascii::_5, Ascii::_6, Ascii::_7,
+    Ascii::_8, Ascii::_9, Ascii::A, Ascii::B,
+    Ascii::C, Ascii::D, Ascii::E, Ascii::F,
+];
+
+/// An iterator over the escaped version of an `Ascii` character, created by
+/// [`Ascii::escape_default`](enum.Ascii.html#method.escape_default).
+#[derive(Clone)]
+pub struct AsciiEscapeDefault {
+    buf: [Ascii; 4],
+    range: Range<usize>,
+}
+
+impl Iterator for AsciiEscapeDefault {
+    type Item = Ascii;
+
+    #[inline]
+    fn next(&mut self) -> Option<Ascii> {
+        self.range.next().map(|i| self.buf[i])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl ExactSizeIterator for AsciiEscapeDefault {
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl DoubleEndedIterator for AsciiEscapeDefault {
+    #[inline]
+    fn next_back(&mut self) -> Option<Ascii> {
+        self.range.next_back().map(|i| self.buf[i])
+    }
 }
 
 impl fmt::Display for Ascii {
@@ -472,6 +676,110 @@ impl fmt::Debug for Ascii {
      }
 }
 
+/// A borrowed string of ASCII characters, akin to `str` but for `[Ascii]`.
+///
+/// Because every `Ascii` is guaranteed to be no greater than `0x7F`, a
+/// `&[Ascii]` can be reinterpreted as a `&str` (and a `&str` as a
+/// `&[Ascii]`, once validated) without copying or allocating.
+#[repr(transparent)]
+pub struct AsciiStr([Ascii]);
+
+impl AsciiStr {
+    /// Converts a slice of bytes to an `AsciiStr`.
+    ///
+    /// # Failure
+    /// Returns `Err(())` if the slice contains a byte outside of `0..=0x7F`.
+    ///
+    /// # Example
+    /// ```
+    /// # use ascii::AsciiStr;
+    /// let s = AsciiStr::from_bytes(b"ferris").unwrap();
+    /// assert_eq!(s.as_str(), "ferris");
+    /// assert!(AsciiStr::from_bytes(&[0xFF]).is_err());
+    /// ```
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&AsciiStr, ()> {
+        if bytes.iter().all(|&b| b <= 0x7F) {
+            unsafe { Ok(AsciiStr::from_bytes_unchecked(bytes)) }
+        } else {
+            Err(())
+        }
+    }
+
+    /// Converts a slice of bytes to an `AsciiStr` without checking that
+    /// every byte is ASCII.
+    #[inline]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &AsciiStr {
+        transmute(bytes)
+    }
+
+    /// Converts a `&str` to an `AsciiStr`.
+    ///
+    /// # Failure
+    /// Returns `Err(())` if the string contains a non-ASCII character.
+    #[inline]
+    pub fn from_ascii_str(s: &str) -> Result<&AsciiStr, ()> {
+        AsciiStr::from_bytes(s.as_bytes())
+    }
+
+    /// Converts the `AsciiStr` into a `&[u8]`.
+    ///
+    /// This is a pure reinterpret cast; it never allocates or copies.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { transmute(&self.0) }
+    }
+
+    /// Converts the `AsciiStr` into a `&str`.
+    ///
+    /// This is a pure reinterpret cast; it never allocates or copies. It is
+    /// sound because every `Ascii` is `<= 0x7F`, which is always valid UTF-8.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+}
+
+impl Deref for AsciiStr {
+    type Target = [Ascii];
+
+    #[inline]
+    fn deref(&self) -> &[Ascii] {
+        &self.0
+    }
+}
+
+impl Index<usize> for AsciiStr {
+    type Output = Ascii;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Ascii {
+        &self.0[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a AsciiStr {
+    type Item = &'a Ascii;
+    type IntoIter = ::std::slice::Iter<'a, Ascii>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for AsciiStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Debug for AsciiStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 #[cfg(feature = "unstable")]
 impl AsciiExt for Ascii {
     type Owned = Ascii;
@@ -481,16 +789,19 @@ impl AsciiExt for Ascii {
         true
     }
 
+    #[inline]
     fn to_ascii_uppercase(&self) -> Ascii {
-        unsafe{ self.as_byte().to_ascii_uppercase().to_ascii_nocheck() }
+        self.to_uppercase()
     }
 
+    #[inline]
     fn to_ascii_lowercase(&self) -> Ascii {
-        unsafe{ self.as_byte().to_ascii_uppercase().to_ascii_nocheck() }
+        self.to_lowercase()
     }
 
+    #[inline]
     fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
-        self.as_byte().eq_ignore_ascii_case(&other.as_byte())
+        self.eq_ignore_case(other)
     }
 
     #[inline]
@@ -523,21 +834,30 @@ impl<'a> AsciiCast<'a> for char {
 }
 
 
-/// Error returned by `IntoAscii`.
-#[derive(PartialEq)]
-pub struct IntoAsciiError(());
+/// Error returned by `IntoAscii` and the `TryFrom` conversions when a byte
+/// or `char` is not representable as `Ascii`.
+#[derive(PartialEq, Clone, Copy)]
+pub enum IntoAsciiError {
+    /// The offending byte, from a failed `TryFrom<u8>`/`IntoAscii for u8` conversion.
+    Byte(u8),
+    /// The offending character, from a failed `TryFrom<char>`/`IntoAscii for char` conversion.
+    Char(char),
+}
 
 const ERRORMSG_CHAR: &'static str = "not an ASCII character";
 
 impl fmt::Debug for IntoAsciiError {
     fn fmt(&self,  fmtr: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmtr, "{}", ERRORMSG_CHAR)
+        fmt::Display::fmt(self, fmtr)
     }
 }
 
 impl fmt::Display for IntoAsciiError {
     fn fmt(&self,  fmtr: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmtr, "{}", ERRORMSG_CHAR)
+        match *self {
+            IntoAsciiError::Byte(b) => write!(fmtr, "byte {:#04X} is not ASCII", b),
+            IntoAsciiError::Char(c) => write!(fmtr, "char {:?} is not ASCII", c),
+        }
     }
 }
 
@@ -571,7 +891,7 @@ impl IntoAscii for u8 {
         unsafe{if self <= 0x7F {
             return Ok(self.into_ascii_unchecked());
         }}
-        Err(IntoAsciiError(()))
+        Err(IntoAsciiError::Byte(self))
     }
     unsafe fn into_ascii_unchecked(self) -> Ascii {
         transmute(self)
@@ -583,7 +903,7 @@ impl IntoAscii for char {
         unsafe{if self as u32 <= 0x7F {
             return Ok(self.into_ascii_unchecked());
         }}
-        Err(IntoAsciiError(()))
+        Err(IntoAsciiError::Char(self))
     }
     unsafe fn into_ascii_unchecked(self) -> Ascii {
         (self as u8).into_ascii_unchecked()
@@ -591,10 +911,44 @@ impl IntoAscii for char {
 }
 
 
+impl From<Ascii> for u8 {
+    #[inline]
+    fn from(ascii: Ascii) -> u8 {
+        ascii.as_byte()
+    }
+}
+
+impl From<Ascii> for char {
+    #[inline]
+    fn from(ascii: Ascii) -> char {
+        ascii.as_char()
+    }
+}
+
+impl TryFrom<u8> for Ascii {
+    type Error = IntoAsciiError;
+
+    #[inline]
+    fn try_from(byte: u8) -> Result<Ascii, IntoAsciiError> {
+        byte.into_ascii()
+    }
+}
+
+impl TryFrom<char> for Ascii {
+    type Error = IntoAsciiError;
+
+    #[inline]
+    fn try_from(ch: char) -> Result<Ascii, IntoAsciiError> {
+        ch.into_ascii()
+    }
+}
+
+
 #[cfg(test)]
 mod tests {
+    use std::convert::TryFrom;
     use AsciiCast;
-    use super::{Ascii,IntoAscii,IntoAsciiError};
+    use super::{Ascii,AsciiStr,IntoAscii,IntoAsciiError};
 
     #[test]
     fn to_ascii() {
@@ -650,4 +1004,143 @@ mod tests {
     fn fmt_debug_ascii() {
         assert_eq!(format!("{:?}", Ascii::t), "'t'".to_string());
     }
+
+    #[test]
+    fn ascii_str_from_bytes() {
+        let s = AsciiStr::from_bytes(b"ferris").unwrap();
+        assert_eq!(s.as_str(), "ferris");
+        assert_eq!(s.as_bytes(), b"ferris");
+        assert!(AsciiStr::from_bytes(&[0xFF]).is_err());
+    }
+
+    #[test]
+    fn ascii_str_from_ascii_str() {
+        let s = AsciiStr::from_ascii_str("ferris").unwrap();
+        assert_eq!(s.as_str(), "ferris");
+        assert!(AsciiStr::from_ascii_str("λ").is_err());
+    }
+
+    #[test]
+    fn ascii_str_index_and_iter() {
+        let s = AsciiStr::from_ascii_str("ab").unwrap();
+        assert_eq!(s[0], Ascii::a);
+        assert_eq!(s.into_iter().collect::<Vec<_>>(), vec![&Ascii::a, &Ascii::b]);
+    }
+
+    #[test]
+    fn ascii_str_fmt() {
+        let s = AsciiStr::from_ascii_str("ab").unwrap();
+        assert_eq!(format!("{}", s), "ab");
+        assert_eq!(format!("{:?}", s), "\"ab\"");
+    }
+
+    #[test]
+    fn try_from_u8() {
+        assert_eq!(Ascii::try_from(65u8), Ok(Ascii::A));
+        assert_eq!(Ascii::try_from(255u8), Err(IntoAsciiError::Byte(255)));
+    }
+
+    #[test]
+    fn try_from_char() {
+        assert_eq!(Ascii::try_from('A'), Ok(Ascii::A));
+        assert_eq!(Ascii::try_from('λ'), Err(IntoAsciiError::Char('λ')));
+    }
+
+    #[test]
+    fn from_ascii() {
+        assert_eq!(u8::from(Ascii::A), 65u8);
+        assert_eq!(char::from(Ascii::A), 'A');
+    }
+
+    #[test]
+    fn into_ascii_error_display() {
+        assert_eq!(format!("{}", IntoAsciiError::Byte(0xE9)), "byte 0xE9 is not ASCII");
+    }
+
+    #[test]
+    fn to_uppercase() {
+        assert_eq!(Ascii::a.to_uppercase(), Ascii::A);
+        assert_eq!(Ascii::A.to_uppercase(), Ascii::A);
+        assert_eq!(Ascii::Dot.to_uppercase(), Ascii::Dot);
+    }
+
+    #[test]
+    fn to_lowercase() {
+        assert_eq!(Ascii::A.to_lowercase(), Ascii::a);
+        assert_eq!(Ascii::a.to_lowercase(), Ascii::a);
+        assert_eq!(Ascii::Dot.to_lowercase(), Ascii::Dot);
+    }
+
+    #[test]
+    fn eq_ignore_case() {
+        assert!(Ascii::A.eq_ignore_case(&Ascii::a));
+        assert!(Ascii::a.eq_ignore_case(&Ascii::a));
+        assert!(!Ascii::A.eq_ignore_case(&Ascii::B));
+    }
+
+    #[test]
+    fn to_digit() {
+        assert_eq!(Ascii::_7.to_digit(10), Some(7));
+        assert_eq!(Ascii::f.to_digit(16), Some(15));
+        assert_eq!(Ascii::F.to_digit(16), Some(15));
+        assert_eq!(Ascii::g.to_digit(16), None);
+        assert_eq!(Ascii::_9.to_digit(2), None);
+        assert_eq!(Ascii::Dot.to_digit(36), None);
+    }
+
+    #[test]
+    fn to_digit_rejects_controls_aliasing_digits() {
+        assert_eq!(Ascii::DC1.to_digit(10), None);
+        assert_eq!(Ascii::DLE.to_digit(16), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_digit_bad_radix() {
+        Ascii::_0.to_digit(37);
+    }
+
+    #[test]
+    fn from_digit() {
+        assert_eq!(Ascii::from_digit(1, 2), Some(Ascii::_1));
+        assert_eq!(Ascii::from_digit(9, 10), Some(Ascii::_9));
+        assert_eq!(Ascii::from_digit(15, 16), Some(Ascii::f));
+        assert_eq!(Ascii::from_digit(10, 10), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_digit_bad_radix() {
+        Ascii::from_digit(45, 50);
+    }
+
+    #[test]
+    fn escape_default() {
+        assert_eq!(Ascii::Tab.escape_default().collect::<Vec<_>>(),
+                   vec![Ascii::BackSlash, Ascii::t]);
+        assert_eq!(Ascii::LineFeed.escape_default().collect::<Vec<_>>(),
+                   vec![Ascii::BackSlash, Ascii::n]);
+        assert_eq!(Ascii::A.escape_default().collect::<Vec<_>>(), vec![Ascii::A]);
+        assert_eq!(Ascii::Null.escape_default().collect::<Vec<_>>(),
+                   vec![Ascii::BackSlash, Ascii::x, Ascii::_0, Ascii::_0]);
+        assert_eq!(Ascii::DEL.escape_default().collect::<Vec<_>>(),
+                   vec![Ascii::BackSlash, Ascii::x, Ascii::_7, Ascii::F]);
+    }
+
+    #[test]
+    fn escape_default_len() {
+        assert_eq!(Ascii::A.escape_default().len(), 1);
+        assert_eq!(Ascii::Tab.escape_default().len(), 2);
+        assert_eq!(Ascii::Null.escape_default().len(), 4);
+    }
+
+    #[test]
+    fn escape_default_double_ended() {
+        let mut iter = Ascii::Null.escape_default();
+        assert_eq!(iter.next(), Some(Ascii::BackSlash));
+        assert_eq!(iter.next_back(), Some(Ascii::_0));
+        assert_eq!(iter.next_back(), Some(Ascii::_0));
+        assert_eq!(iter.next(), Some(Ascii::x));
+        assert_eq!(iter.next(), None);
+    }
 }